@@ -0,0 +1,208 @@
+use tokio::{fs::File, io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, SeekFrom}};
+use std::io::Read;
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+
+use crate::error;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const FEXTRA: u8 = 0x04;
+const GZIP_HEADER_LEN: usize = 12;
+
+/// A `.gzi` index mapping uncompressed byte offsets onto the BGZF block that
+/// contains them, as emitted by `bgzip -i`.
+///
+/// The file is a little-endian binary blob: a `u64` count followed by that
+/// many `(compressed_offset, uncompressed_offset)` pairs, each a virtual
+/// offset pair for the start of a block. Entries are sorted by offset.
+#[derive(Debug, Clone)]
+pub struct GziIndex
+{
+	entries: Vec<(u64, u64)>,
+}
+
+impl GziIndex
+{
+	pub async fn from_path<P>(path: P) -> error::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		let mut file = File::open(path).await?;
+		let mut contents = Vec::new();
+		file.read_to_end(&mut contents).await?;
+		Self::from_bytes(&contents)
+	}
+
+	pub async fn from_reader<R>(mut reader: R) -> error::Result<Self>
+	where
+		R: AsyncRead + std::marker::Send + std::marker::Unpin,
+	{
+		let mut contents = Vec::new();
+		reader.read_to_end(&mut contents).await?;
+		Self::from_bytes(&contents)
+	}
+
+	fn from_bytes(contents: &[u8]) -> error::Result<Self>
+	{
+		if contents.len() < 8
+		{
+			return Err(error::Error::ParseError);
+		}
+
+		let count = u64::from_le_bytes(contents[0..8].try_into().unwrap()) as usize;
+		let mut entries = Vec::with_capacity(count);
+
+		let mut cursor = 8;
+		for _ in 0..count
+		{
+			if cursor + 16 > contents.len()
+			{
+				return Err(error::Error::ParseError);
+			}
+
+			let compressed_offset = u64::from_le_bytes(contents[cursor..cursor + 8].try_into().unwrap());
+			let uncompressed_offset =
+				u64::from_le_bytes(contents[cursor + 8..cursor + 16].try_into().unwrap());
+			entries.push((compressed_offset, uncompressed_offset));
+			cursor += 16;
+		}
+
+		// The start of the file is always a valid block boundary, but bgzip
+		// does not record it explicitly.
+		if entries.first().map(|&(_, u)| u) != Some(0)
+		{
+			entries.insert(0, (0, 0));
+		}
+
+		Ok(Self { entries })
+	}
+
+	/// Returns the `(compressed_offset, uncompressed_offset)` of the block
+	/// boundary at or before `uncompressed_offset`.
+	fn block_containing(&self, uncompressed_offset: u64) -> (u64, u64)
+	{
+		match self.entries.partition_point(|&(_, u)| u <= uncompressed_offset)
+		{
+			0 => self.entries[0],
+			index => self.entries[index - 1],
+		}
+	}
+}
+
+/// Reads one BGZF block's compressed bytes starting at the reader's current
+/// position and inflates it, returning its plaintext. `reader` is left
+/// positioned at the start of the next block.
+///
+/// Parses the standard gzip header, walking its `FEXTRA` subfields to find
+/// the BGZF `BC` subfield, whose payload is the total compressed block size
+/// (`BSIZE`) minus one. This bounds the read to exactly this block instead
+/// of inflating everything up to EOF.
+async fn read_one_block<R>(reader: &mut R) -> error::Result<Vec<u8>>
+where
+	R: AsyncRead + std::marker::Unpin,
+{
+	let mut header = [0u8; GZIP_HEADER_LEN];
+	reader.read_exact(&mut header).await?;
+
+	if header[0..2] != GZIP_MAGIC
+	{
+		return Err(error::Error::ParseError);
+	}
+
+	if header[3] & FEXTRA == 0
+	{
+		return Err(error::Error::ParseError);
+	}
+
+	let xlen = u16::from_le_bytes([header[10], header[11]]) as usize;
+	let mut extra = vec![0u8; xlen];
+	reader.read_exact(&mut extra).await?;
+
+	let bsize = find_bc_subfield(&extra).ok_or(error::Error::ParseError)?;
+	let block_size = bsize + 1;
+
+	let consumed = (GZIP_HEADER_LEN + xlen) as u64;
+	let remaining = block_size
+		.checked_sub(consumed)
+		.ok_or(error::Error::ParseError)?;
+
+	let mut body = vec![0u8; remaining as usize];
+	reader.read_exact(&mut body).await?;
+
+	let mut block = Vec::with_capacity(block_size as usize);
+	block.extend_from_slice(&header);
+	block.extend_from_slice(&extra);
+	block.extend_from_slice(&body);
+
+	let mut plaintext = Vec::new();
+	GzDecoder::new(&block[..])
+		.read_to_end(&mut plaintext)
+		.map_err(|_| error::Error::ParseError)?;
+
+	Ok(plaintext)
+}
+
+/// Scans a gzip `FEXTRA` payload for the BGZF `BC` subfield and returns its
+/// `BSIZE` value (the total block size minus one).
+fn find_bc_subfield(extra: &[u8]) -> Option<u64>
+{
+	let mut cursor = 0;
+
+	while cursor + 4 <= extra.len()
+	{
+		let subfield_id = [extra[cursor], extra[cursor + 1]];
+		let subfield_len = u16::from_le_bytes([extra[cursor + 2], extra[cursor + 3]]) as usize;
+		let data_start = cursor + 4;
+
+		if subfield_id == [b'B', b'C'] && subfield_len == 2 && data_start + 2 <= extra.len()
+		{
+			return Some(u16::from_le_bytes([extra[data_start], extra[data_start + 1]]) as u64);
+		}
+
+		cursor = data_start + subfield_len;
+	}
+
+	None
+}
+
+/// Reads the uncompressed byte range `[start, end)` out of a BGZF-compressed
+/// `reader`, using `gzi` to translate it into a compressed block seek plus
+/// in-block skip. Only the blocks spanning `[start, end)` are read and
+/// inflated, not the remainder of the file.
+pub(crate) async fn read_range<R>(reader: &mut R, gzi: &GziIndex, start: u64, end: u64) -> error::Result<Vec<u8>>
+where
+	R: AsyncRead + AsyncSeek + std::marker::Unpin,
+{
+	let (block_start, block_uncompressed_start) = gzi.block_containing(start);
+
+	reader.seek(SeekFrom::Start(block_start)).await?;
+
+	let mut decompressed = Vec::new();
+	let mut uncompressed_pos = block_uncompressed_start;
+
+	while uncompressed_pos < end
+	{
+		let plaintext = read_one_block(reader).await?;
+
+		// The empty, 28-byte BGZF EOF marker decompresses to nothing; stop
+		// rather than looping forever if `end` reaches past real data.
+		if plaintext.is_empty()
+		{
+			break;
+		}
+
+		uncompressed_pos += plaintext.len() as u64;
+		decompressed.extend_from_slice(&plaintext);
+	}
+
+	let skip = (start - block_uncompressed_start) as usize;
+	let len = (end - start) as usize;
+
+	if skip + len > decompressed.len()
+	{
+		return Err(error::Error::InvalidRegion);
+	}
+
+	Ok(decompressed[skip..skip + len].to_vec())
+}
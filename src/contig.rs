@@ -10,6 +10,7 @@ use tokio::sync::Mutex;
 use async_trait::async_trait;
 
 use crate::FaiIndex;
+use crate::bgzf::{self, GziIndex};
 use crate::error;
 
 #[async_trait]
@@ -46,6 +47,7 @@ pub struct FileContig<R>
 {
 	pub tid: String,
 	pub index: Option<Arc<FaiIndex>>,
+	pub gzi: Option<Arc<GziIndex>>,
 	pub reader: Arc<Mutex<R>>,
 }
 
@@ -64,10 +66,17 @@ where
 			.ok()?;
 
 		let mut reader = self.reader.lock().await;
-		reader.seek(SeekFrom::Start(file_start)).await.ok()?;
-
-		let mut buf = vec![0; (file_end - file_start) as usize];
-		reader.read_exact(&mut buf).await.ok()?;
+		let buf = match self.gzi.as_ref()
+		{
+			Some(gzi) => bgzf::read_range(&mut *reader, gzi, file_start, file_end).await.ok()?,
+			None =>
+			{
+				reader.seek(SeekFrom::Start(file_start)).await.ok()?;
+				let mut buf = vec![0; (file_end - file_start) as usize];
+				reader.read_exact(&mut buf).await.ok()?;
+				buf
+			}
+		};
 
 		Some(
 			buf.into_iter()
@@ -85,10 +94,18 @@ where
 			.as_ref()?
 			.get_region_offsets(&self.tid, start, end)?;
 		let mut reader = self.reader.lock().await;
-		reader.seek(SeekFrom::Start(file_start)).await.ok()?;
+		let buf = match self.gzi.as_ref()
+		{
+			Some(gzi) => bgzf::read_range(&mut *reader, gzi, file_start, file_end).await.ok()?,
+			None =>
+			{
+				reader.seek(SeekFrom::Start(file_start)).await.ok()?;
+				let mut buf = vec![0; (file_end - file_start) as usize];
+				reader.read_exact(&mut buf).await.ok()?;
+				buf
+			}
+		};
 
-		let mut buf = vec![0; (file_end - file_start) as usize];
-		reader.read_exact(&mut buf).await.ok()?;
 		Some(
 			buf.into_iter()
 				.filter(|&b| b != b'\n' && b != b'\r')
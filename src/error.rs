@@ -11,6 +11,8 @@ pub enum Error
 	ParseError,
 	#[error("Unable to read FASTA region due to region specified being invalid")]
 	InvalidRegion,
+	#[error("operation not supported on BGZF-compressed input")]
+	BgzfUnsupported,
 	#[error("IO error: {0}")]
 	Io(#[from] std::io::Error),
 }
@@ -1,4 +1,4 @@
-use tokio::{fs::File, io::{AsyncRead, AsyncBufReadExt, BufReader}};
+use tokio::{fs::File, io::{AsyncRead, AsyncWrite, AsyncBufReadExt, AsyncWriteExt, BufReader}};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -77,4 +77,194 @@ impl FaiIndex
 		let entry = self.entries.get(tid)?;
 		Some((entry.offset, entry.offset + entry.length))
 	}
+
+	pub async fn build_from_path<P>(path: P) -> error::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		let file = File::open(path).await?;
+		Self::build_from_reader(file).await
+	}
+
+	/// Scans a FASTA file and builds the equivalent `.fai` index in memory.
+	///
+	/// Every sequence line must have the same `line_bases`/`line_width` as the
+	/// first line of its entry, except for the last line, matching the
+	/// invariant `get_region_offsets` relies on. Violations are reported as
+	/// `error::Error::ParseError`.
+	pub async fn build_from_reader<R>(reader: R) -> error::Result<Self>
+	where
+		R: AsyncRead + std::marker::Send + std::marker::Unpin,
+	{
+		let mut reader = BufReader::new(reader);
+		let mut entries = HashMap::new();
+
+		let mut pos: u64 = 0;
+		let mut current_name: Option<String> = None;
+		let mut current_offset: u64 = 0;
+		let mut lines: Vec<(u64, u64)> = Vec::new();
+		let mut line = Vec::new();
+
+		loop
+		{
+			line.clear();
+			let read = reader.read_until(b'\n', &mut line).await?;
+			if read == 0
+			{
+				break;
+			}
+
+			if line[0] == b'>'
+			{
+				if let Some(name) = current_name.take()
+				{
+					let entry = Self::finish_entry(name, current_offset, &lines)?;
+					entries.insert(entry.name.clone(), entry);
+				}
+				lines.clear();
+
+				let header = std::str::from_utf8(&line).map_err(|_| error::Error::ParseError)?;
+				let name = header[1..]
+					.split_whitespace()
+					.next()
+					.ok_or(error::Error::ParseError)?
+					.to_string();
+
+				pos += read as u64;
+				current_offset = pos;
+				current_name = Some(name);
+				continue;
+			}
+
+			let bases = line
+				.iter()
+				.rev()
+				.skip_while(|&&byte| byte == b'\n' || byte == b'\r')
+				.count() as u64;
+
+			lines.push((bases, read as u64));
+			pos += read as u64;
+		}
+
+		if let Some(name) = current_name.take()
+		{
+			let entry = Self::finish_entry(name, current_offset, &lines)?;
+			entries.insert(entry.name.clone(), entry);
+		}
+
+		Ok(Self { entries })
+	}
+
+	fn finish_entry(name: String, offset: u64, lines: &[(u64, u64)]) -> error::Result<FaiEntry>
+	{
+		let (line_bases, line_width) = lines.first().copied().unwrap_or((0, 0));
+
+		if let Some((_, body)) = lines.split_last()
+		{
+			let mismatched = body
+				.iter()
+				.any(|&(bases, width)| bases != line_bases || width != line_width);
+
+			if mismatched
+			{
+				return Err(error::Error::ParseError);
+			}
+		}
+
+		Ok(FaiEntry {
+			name,
+			length: lines.iter().map(|&(bases, _)| bases).sum(),
+			offset,
+			line_bases,
+			line_width,
+		})
+	}
+
+	pub async fn write_to_path<P>(&self, path: P) -> error::Result<()>
+	where
+		P: AsRef<Path>,
+	{
+		let file = File::create(path).await?;
+		self.write_to(file).await
+	}
+
+	pub async fn write_to<W>(&self, writer: W) -> error::Result<()>
+	where
+		W: AsyncWrite + std::marker::Unpin,
+	{
+		let mut writer = writer;
+		let mut entries: Vec<&FaiEntry> = self.entries.values().collect();
+		entries.sort_by_key(|entry| entry.offset);
+
+		for entry in entries
+		{
+			let line = format!(
+				"{}\t{}\t{}\t{}\t{}\n",
+				entry.name, entry.length, entry.offset, entry.line_bases, entry.line_width
+			);
+			writer.write_all(line.as_bytes()).await?;
+		}
+
+		writer.flush().await?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use tempfile::tempdir;
+	use tokio::fs::File;
+	use tokio::io::AsyncWriteExt;
+
+	#[tokio::test]
+	async fn test_build_from_reader_matches_samtools_fai()
+	{
+		let fasta = b">chr1 some description\nACGTACGTAC\nGTACGTACGT\nACGT\n>chr2\nACGT\n";
+
+		let index = FaiIndex::build_from_reader(&fasta[..]).await.unwrap();
+
+		let chr1 = &index.entries["chr1"];
+		assert_eq!(chr1.length, 24);
+		assert_eq!(chr1.offset, 23);
+		assert_eq!(chr1.line_bases, 10);
+		assert_eq!(chr1.line_width, 11);
+
+		let chr2 = &index.entries["chr2"];
+		assert_eq!(chr2.length, 4);
+		assert_eq!(chr2.line_bases, 4);
+		assert_eq!(chr2.line_width, 5);
+	}
+
+	#[tokio::test]
+	async fn test_build_from_reader_rejects_ragged_lines()
+	{
+		let fasta = b">chr1\nACGTACGTAC\nACGT\nACGTACGTAC\n";
+
+		let result = FaiIndex::build_from_reader(&fasta[..]).await;
+		assert!(matches!(result.unwrap_err(), error::Error::ParseError));
+	}
+
+	#[tokio::test]
+	async fn test_build_from_path_and_write_to_roundtrip()
+	{
+		let dir = tempdir().unwrap();
+		let fasta_path = dir.path().join("test.fasta");
+		let fai_path = dir.path().join("test.fasta.fai");
+
+		let mut fasta_file = File::create(&fasta_path).await.unwrap();
+		fasta_file
+			.write_all(b">chr1\nACGTACGTACGT\n")
+			.await
+			.unwrap();
+		fasta_file.flush().await.unwrap();
+
+		let built = FaiIndex::build_from_path(&fasta_path).await.unwrap();
+		built.write_to_path(&fai_path).await.unwrap();
+
+		let reloaded = FaiIndex::from_path(&fai_path).await.unwrap();
+		assert_eq!(reloaded.entries["chr1"].length, built.entries["chr1"].length);
+		assert_eq!(reloaded.entries["chr1"].offset, built.entries["chr1"].offset);
+	}
 }
@@ -1,5 +1,5 @@
 use tokio::fs::File;
-use tokio::io::{SeekFrom, AsyncSeekExt, AsyncRead, AsyncSeek, AsyncReadExt, BufReader};
+use tokio::io::{SeekFrom, AsyncSeekExt, AsyncRead, AsyncSeek, AsyncReadExt, AsyncBufReadExt, BufReader};
 use tokio::sync::Mutex;
 
 use std::path::Path;
@@ -7,8 +7,9 @@ use std::collections::HashMap;
 use std::borrow::Cow;
 use std::sync::Arc;
 
+use crate::bgzf;
 use crate::error;
-use crate::{FaiIndex, Contig, MemoryContig, FileContig};
+use crate::{FaiIndex, GziIndex, Contig, MemoryContig, FileContig};
 
 pub struct Fasta<R>
 where
@@ -16,6 +17,7 @@ where
 {
 	reader: Arc<Mutex<BufReader<R>>>,
 	index: Option<Arc<FaiIndex>>,
+	gzi: Option<Arc<GziIndex>>,
 }
 
 impl Fasta<File>
@@ -56,6 +58,64 @@ impl Fasta<File>
 		Ok(Fasta {
 			reader,
 			index: fai_index,
+			gzi: None,
+		})
+	}
+
+	/// Opens a BGZF block-gzip compressed reference (`.fa.gz`), using a
+	/// `.gzi` virtual-offset index to translate the FAI-derived uncompressed
+	/// coordinates into compressed block seeks.
+	///
+	/// `fai_path` and `gzi_path` are auto-detected next to `fasta_path` the
+	/// same way `from_path` auto-detects the plain `.fai`, unless given
+	/// explicitly.
+	pub async fn from_path_bgzf<P>(
+		fasta_path: P,
+		fai_path: Option<P>,
+		gzi_path: Option<P>,
+	) -> error::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		let fasta_path = fasta_path.as_ref();
+
+		let fai_index = match fai_path
+		{
+			Some(fai_path) => FaiIndex::from_path(fai_path).await?,
+			None =>
+			{
+				let fai_path = fasta_path.with_extension(
+					fasta_path
+						.extension()
+						.map(|ext| format!("{}.fai", ext.to_string_lossy()))
+						.unwrap_or_else(|| "fai".to_string()),
+				);
+				FaiIndex::from_path(fai_path).await?
+			}
+		};
+
+		let gzi_index = match gzi_path
+		{
+			Some(gzi_path) => GziIndex::from_path(gzi_path).await?,
+			None =>
+			{
+				let gzi_path = fasta_path.with_extension(
+					fasta_path
+						.extension()
+						.map(|ext| format!("{}.gzi", ext.to_string_lossy()))
+						.unwrap_or_else(|| "gzi".to_string()),
+				);
+				GziIndex::from_path(gzi_path).await?
+			}
+		};
+
+		let file = File::open(fasta_path).await?;
+		let reader = Arc::new(Mutex::new(BufReader::new(file)));
+
+		Ok(Fasta {
+			reader,
+			index: Some(Arc::new(fai_index)),
+			gzi: Some(Arc::new(gzi_index)),
 		})
 	}
 }
@@ -78,6 +138,7 @@ where
 		Ok(Fasta {
 			reader: reader,
 			index: fai_index,
+			gzi: None,
 		})
 	}
 
@@ -91,10 +152,18 @@ where
 			.ok_or_else(|| error::Error::InvalidRegion)?;
 
 		let mut reader = self.reader.lock().await;
-		reader.seek(tokio::io::SeekFrom::Start(file_start)).await?;
 
-		let mut buf = vec![0u8; (file_end - file_start) as usize];
-		reader.read_exact(&mut buf).await?;
+		let buf = match self.gzi.as_ref()
+		{
+			Some(gzi) => bgzf::read_range(&mut *reader, gzi, file_start, file_end).await?,
+			None =>
+			{
+				reader.seek(tokio::io::SeekFrom::Start(file_start)).await?;
+				let mut buf = vec![0u8; (file_end - file_start) as usize];
+				reader.read_exact(&mut buf).await?;
+				buf
+			}
+		};
 
 		Ok(buf
 			.into_iter()
@@ -103,6 +172,31 @@ where
 			.collect())
 	}
 
+	/// Resolves a samtools-style region string (`chr1:1,000-2,000`) against
+	/// the index and reads it, defaulting the end to the contig length when
+	/// omitted.
+	pub async fn read_region_str(&mut self, region: &str) -> error::Result<String>
+	{
+		let (_, region) = crate::parser::parse_region(region).map_err(|_| error::Error::InvalidRegion)?;
+
+		let end = match region.end
+		{
+			Some(end) => end,
+			None =>
+			{
+				self.index
+					.as_ref()
+					.ok_or(error::Error::NoFAIDX)?
+					.entries
+					.get(&region.name)
+					.ok_or(error::Error::InvalidRegion)?
+					.length
+			}
+		};
+
+		self.read_region(&region.name, region.start, end).await
+	}
+
 	pub fn tid_lengths(&self) -> error::Result<Vec<(String, u64)>>
 	{
 		Ok(self
@@ -161,10 +255,18 @@ where
 			.ok_or(error::Error::InvalidRegion)?;
 
 		let mut reader = self.reader.lock().await;
-		reader.seek(SeekFrom::Start(file_start)).await?;
 
-		let mut buf = vec![0u8; (file_end - file_start) as usize];
-		reader.read_exact(&mut buf).await?;
+		let buf = match self.gzi.as_ref()
+		{
+			Some(gzi) => bgzf::read_range(&mut *reader, gzi, file_start, file_end).await?,
+			None =>
+			{
+				reader.seek(SeekFrom::Start(file_start)).await?;
+				let mut buf = vec![0u8; (file_end - file_start) as usize];
+				reader.read_exact(&mut buf).await?;
+				buf
+			}
+		};
 
 		let sequence: String = buf
 			.into_iter()
@@ -185,10 +287,130 @@ where
 			source: Box::new(FileContig {
 				tid: tid.to_string(),
 				index: self.index.as_ref().map(Arc::clone),
+				gzi: self.gzi.as_ref().map(Arc::clone),
 				reader: Arc::clone(&self.reader),
 			}),
 		})
 	}
+
+	/// Returns a sequential record iterator over the whole FASTA, rewound to
+	/// the start of the file. Unlike `read_region`/`read_mmap_tid` this works
+	/// without an `FaiIndex` - it just parses headers and sequence lines as
+	/// it goes.
+	///
+	/// Not supported on BGZF-compressed input: it reads raw bytes off the
+	/// underlying reader rather than going through `bgzf::read_range`, so it
+	/// would scan compressed bytes looking for a literal `'>'` instead of the
+	/// decompressed sequence. Returns `error::Error::BgzfUnsupported` if this
+	/// `Fasta` was opened with a `.gzi` index.
+	pub async fn records(&mut self) -> error::Result<FastaRecords<R>>
+	{
+		if self.gzi.is_some()
+		{
+			return Err(error::Error::BgzfUnsupported);
+		}
+
+		{
+			let mut reader = self.reader.lock().await;
+			reader.seek(SeekFrom::Start(0)).await?;
+		}
+
+		Ok(FastaRecords {
+			reader: Arc::clone(&self.reader),
+			pending_header: None,
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastaRecord
+{
+	pub name: String,
+	pub description: Option<String>,
+	pub sequence: String,
+}
+
+/// A sequential, index-free reader over FASTA records, produced by
+/// `Fasta::records`. Call `next_record` in a loop until it returns `None`,
+/// mirroring `tokio::io::Lines::next_line`.
+pub struct FastaRecords<R>
+where
+	R: AsyncRead + AsyncSeek + std::marker::Send + std::marker::Unpin + 'static,
+{
+	reader: Arc<Mutex<BufReader<R>>>,
+	pending_header: Option<Vec<u8>>,
+}
+
+impl<R> FastaRecords<R>
+where
+	R: AsyncRead + AsyncSeek + std::marker::Send + std::marker::Unpin + 'static,
+{
+	pub async fn next_record(&mut self) -> error::Result<Option<FastaRecord>>
+	{
+		let mut reader = self.reader.lock().await;
+
+		let header_line = match self.pending_header.take()
+		{
+			Some(line) => line,
+			None =>
+			{
+				let mut line = Vec::new();
+				loop
+				{
+					line.clear();
+					if reader.read_until(b'\n', &mut line).await? == 0
+					{
+						return Ok(None);
+					}
+
+					if line[0] == b'>'
+					{
+						break;
+					}
+				}
+				line
+			}
+		};
+
+		let header = std::str::from_utf8(trim_newline(&header_line)).map_err(|_| error::Error::ParseError)?;
+		let mut parts = header[1..].splitn(2, char::is_whitespace);
+		let name = parts.next().unwrap_or("").to_string();
+		let description = parts.next().map(str::trim_start).filter(|s| !s.is_empty()).map(str::to_string);
+
+		let mut sequence = String::new();
+		let mut line = Vec::new();
+
+		loop
+		{
+			line.clear();
+			if reader.read_until(b'\n', &mut line).await? == 0
+			{
+				break;
+			}
+
+			if line[0] == b'>'
+			{
+				self.pending_header = Some(line);
+				break;
+			}
+
+			sequence.push_str(
+				std::str::from_utf8(trim_newline(&line)).map_err(|_| error::Error::ParseError)?,
+			);
+		}
+
+		Ok(Some(FastaRecord { name, description, sequence }))
+	}
+}
+
+fn trim_newline(line: &[u8]) -> &[u8]
+{
+	let mut end = line.len();
+	while end > 0 && (line[end - 1] == b'\n' || line[end - 1] == b'\r')
+	{
+		end -= 1;
+	}
+	&line[..end]
 }
 
 pub trait ReverseComplement
@@ -196,22 +418,48 @@ pub trait ReverseComplement
 	fn reverse_complement(&self) -> String;
 }
 
+/// Complements a single IUPAC ambiguity code, preserving case. `-`/`.` gap
+/// characters pass through unchanged; anything else unrecognized falls back
+/// to `N`.
+fn complement_base(c: char) -> char
+{
+	let complement = match c.to_ascii_uppercase()
+	{
+		'A' => 'T',
+		'T' | 'U' => 'A',
+		'C' => 'G',
+		'G' => 'C',
+		'R' => 'Y',
+		'Y' => 'R',
+		'S' => 'S',
+		'W' => 'W',
+		'K' => 'M',
+		'M' => 'K',
+		'B' => 'V',
+		'V' => 'B',
+		'D' => 'H',
+		'H' => 'D',
+		'N' => 'N',
+		'-' => '-',
+		'.' => '.',
+		_ => 'N',
+	};
+
+	if c.is_ascii_lowercase()
+	{
+		complement.to_ascii_lowercase()
+	}
+	else
+	{
+		complement
+	}
+}
+
 impl ReverseComplement for String
 {
 	fn reverse_complement(&self) -> String
 	{
-		self.chars()
-			.rev()
-			.map(|c| match c
-			{
-				'A' | 'a' => 'T',
-				'T' | 't' => 'A',
-				'C' | 'c' => 'G',
-				'G' | 'g' => 'C',
-				'N' | 'n' => 'N',
-				_ => 'N',
-			})
-			.collect()
+		self.chars().rev().map(complement_base).collect()
 	}
 }
 
@@ -219,18 +467,7 @@ impl ReverseComplement for &str
 {
 	fn reverse_complement(&self) -> String
 	{
-		self.chars()
-			.rev()
-			.map(|c| match c
-			{
-				'A' | 'a' => 'T',
-				'T' | 't' => 'A',
-				'C' | 'c' => 'G',
-				'G' | 'g' => 'C',
-				'N' | 'n' => 'N',
-				_ => 'N',
-			})
-			.collect()
+		self.chars().rev().map(complement_base).collect()
 	}
 }
 
@@ -254,7 +491,23 @@ mod tests
 	{
 		let seq = "atgcn";
 		let rev = seq.reverse_complement();
-		assert_eq!(rev, "NGCAT"); // case-insensitive mapping
+		assert_eq!(rev, "ngcat"); // lowercase input stays lowercase
+	}
+
+	#[test]
+	fn test_reverse_complement_iupac_ambiguity_codes()
+	{
+		let seq = "RYSWKMBDHVrywkmbdhv".to_string();
+		let rev = seq.reverse_complement();
+		assert_eq!(rev, "bdhvkmwryBDHVKMWSRY");
+	}
+
+	#[test]
+	fn test_reverse_complement_preserves_gaps()
+	{
+		let seq = "AC-GT.N".to_string();
+		let rev = seq.reverse_complement();
+		assert_eq!(rev, "N.AC-GT");
 	}
 
 	#[test]
@@ -332,6 +585,39 @@ mod tests
 		assert_eq!(seq, "ACGT");
 	}
 
+	#[tokio::test]
+	async fn test_read_region_str_explicit_range()
+	{
+		let (_dir, fasta_path, fai_path) = create_test_fasta_and_fai().await;
+
+		let mut fasta = Fasta::from_path(fasta_path, Some(fai_path)).await.unwrap();
+
+		let seq = fasta.read_region_str("chr1:1-4").await.unwrap();
+		assert_eq!(seq, "ACGT");
+	}
+
+	#[tokio::test]
+	async fn test_read_region_str_defaults_end_to_contig_length()
+	{
+		let (_dir, fasta_path, fai_path) = create_test_fasta_and_fai().await;
+
+		let mut fasta = Fasta::from_path(fasta_path, Some(fai_path)).await.unwrap();
+
+		let seq = fasta.read_region_str("chr1:5").await.unwrap();
+		assert_eq!(seq, "ACGTACGT");
+	}
+
+	#[tokio::test]
+	async fn test_read_region_str_invalid_contig()
+	{
+		let (_dir, fasta_path, fai_path) = create_test_fasta_and_fai().await;
+
+		let mut fasta = Fasta::from_path(fasta_path, Some(fai_path)).await.unwrap();
+
+		let result = fasta.read_region_str("chr99:1-4").await;
+		assert!(matches!(result.unwrap_err(), error::Error::InvalidRegion));
+	}
+
 	#[tokio::test]
 	async fn test_read_region_invalid_region()
 	{
@@ -419,4 +705,148 @@ mod tests
 		let seq = all.get_mut("chr1").unwrap().sequence().await.unwrap();
 		assert_eq!(seq, "ACGTACGTACGT");
 	}
+
+	/// Builds a single valid BGZF block (standard gzip member plus the `BC`
+	/// `FEXTRA` subfield carrying `BSIZE`, the total block size minus one).
+	fn build_bgzf_block(plaintext: &[u8]) -> Vec<u8>
+	{
+		use flate2::{Compression, GzBuilder};
+		use std::io::Write;
+
+		// SI1, SI2, SLEN (LE), BSIZE (LE placeholder, patched below).
+		let extra = vec![b'B', b'C', 2, 0, 0, 0];
+
+		let mut block = Vec::new();
+		{
+			let mut encoder = GzBuilder::new()
+				.extra(extra)
+				.write(&mut block, Compression::default());
+			encoder.write_all(plaintext).unwrap();
+			encoder.finish().unwrap();
+		}
+
+		let bsize = (block.len() - 1) as u16;
+		block[16..18].copy_from_slice(&bsize.to_le_bytes());
+		block
+	}
+
+	async fn create_test_bgzf_fasta() -> (tempfile::TempDir, String)
+	{
+		let dir = tempdir().unwrap();
+		let fasta_path = dir.path().join("test.fasta.gz");
+		let fai_path = dir.path().join("test.fasta.gz.fai");
+		let gzi_path = dir.path().join("test.fasta.gz.gzi");
+
+		let plaintext = b">chr1\nACGTACGTACGT\n";
+		let compressed = build_bgzf_block(plaintext);
+
+		tokio::fs::write(&fasta_path, &compressed).await.unwrap();
+		tokio::fs::write(&fai_path, b"chr1\t12\t6\t12\t13\n")
+			.await
+			.unwrap();
+
+		// No block boundaries beyond the implicit one at the start of the file.
+		tokio::fs::write(&gzi_path, 0u64.to_le_bytes())
+			.await
+			.unwrap();
+
+		(dir, fasta_path.to_string_lossy().to_string())
+	}
+
+	#[tokio::test]
+	async fn test_read_region_bgzf()
+	{
+		let (_dir, fasta_path) = create_test_bgzf_fasta().await;
+
+		let mut fasta = Fasta::from_path_bgzf(fasta_path, None, None)
+			.await
+			.unwrap();
+
+		let seq = fasta.read_region("chr1", 0, 4).await.unwrap();
+		assert_eq!(seq, "ACGT");
+	}
+
+	#[tokio::test]
+	async fn test_read_region_bgzf_spans_multiple_blocks()
+	{
+		let dir = tempdir().unwrap();
+		let fasta_path = dir.path().join("test.fasta.gz");
+		let fai_path = dir.path().join("test.fasta.gz.fai");
+		let gzi_path = dir.path().join("test.fasta.gz.gzi");
+
+		let plaintext = b">chr1\nAAAACCCCGGGGTTTTAAAA\n";
+		let (first_half, second_half) = plaintext.split_at(15);
+
+		let block1 = build_bgzf_block(first_half);
+		let block2 = build_bgzf_block(second_half);
+
+		let mut compressed = block1.clone();
+		compressed.extend_from_slice(&block2);
+
+		tokio::fs::write(&fasta_path, &compressed).await.unwrap();
+		tokio::fs::write(&fai_path, b"chr1\t20\t6\t20\t21\n")
+			.await
+			.unwrap();
+
+		let mut gzi = Vec::new();
+		gzi.extend_from_slice(&1u64.to_le_bytes());
+		gzi.extend_from_slice(&(block1.len() as u64).to_le_bytes());
+		gzi.extend_from_slice(&15u64.to_le_bytes());
+		tokio::fs::write(&gzi_path, &gzi).await.unwrap();
+
+		let mut fasta = Fasta::from_path_bgzf(
+			fasta_path.to_string_lossy().to_string(),
+			Some(fai_path.to_string_lossy().to_string()),
+			Some(gzi_path.to_string_lossy().to_string()),
+		)
+		.await
+		.unwrap();
+
+		let seq = fasta.read_region("chr1", 0, 20).await.unwrap();
+		assert_eq!(seq, "AAAACCCCGGGGTTTTAAAA");
+	}
+
+	#[tokio::test]
+	async fn test_records_rejects_bgzf()
+	{
+		let (_dir, fasta_path) = create_test_bgzf_fasta().await;
+
+		let mut fasta = Fasta::from_path_bgzf(fasta_path, None, None)
+			.await
+			.unwrap();
+
+		let result = fasta.records().await;
+		assert!(matches!(result, Err(error::Error::BgzfUnsupported)));
+	}
+
+	#[tokio::test]
+	async fn test_records_without_fai()
+	{
+		let dir = tempdir().unwrap();
+		let fasta_path = dir.path().join("test.fasta");
+
+		let mut fasta_file = File::create(&fasta_path).await.unwrap();
+		fasta_file
+			.write_all(b">chr1 first description\nACGT\nACGT\n>chr2\nTTTT\n")
+			.await
+			.unwrap();
+		fasta_file.flush().await.unwrap();
+
+		let mut fasta = Fasta::from_path(&fasta_path, None).await.unwrap();
+		assert!(fasta.index.is_none());
+
+		let mut records = fasta.records().await.unwrap();
+
+		let first = records.next_record().await.unwrap().unwrap();
+		assert_eq!(first.name, "chr1");
+		assert_eq!(first.description.as_deref(), Some("first description"));
+		assert_eq!(first.sequence, "ACGTACGT");
+
+		let second = records.next_record().await.unwrap().unwrap();
+		assert_eq!(second.name, "chr2");
+		assert_eq!(second.description, None);
+		assert_eq!(second.sequence, "TTTT");
+
+		assert!(records.next_record().await.unwrap().is_none());
+	}
 }
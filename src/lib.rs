@@ -1,9 +1,14 @@
+pub mod bgzf;
 pub mod contig;
 pub mod error;
 pub mod fai;
 pub mod fasta;
 mod parser;
+pub mod writer;
 
 pub use fasta::*;
 pub use fai::*;
 pub use contig::*;
+pub use bgzf::*;
+pub use writer::*;
+pub use parser::Region;
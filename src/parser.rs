@@ -1,4 +1,10 @@
-use nom::{bytes::complete::take_until, character::complete::{digit1, line_ending}, combinator::map_res, IResult};
+use nom::{
+	bytes::complete::{take_till1, take_until, take_while1},
+	character::complete::{char, digit1, line_ending},
+	combinator::{all_consuming, map_res, opt},
+	sequence::preceded,
+	IResult,
+};
 use nom::Parser;
 
 use crate::fai::FaiEntry;
@@ -8,6 +14,64 @@ pub(crate) fn parse_u64(input: &str) -> IResult<&str, u64>
 	map_res(digit1, str::parse).parse(input)
 }
 
+/// A samtools-style region, already converted from 1-based inclusive
+/// coordinates to the 0-based half-open range the rest of the crate uses.
+/// `end` is `None` when the caller didn't specify one, leaving it to the
+/// resolver to default it to the contig length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region
+{
+	pub name: String,
+	pub start: u64,
+	pub end: Option<u64>,
+}
+
+fn parse_grouped_u64(input: &str) -> IResult<&str, u64>
+{
+	let (input, raw) = take_while1(|c: char| c.is_ascii_digit() || c == ',').parse(input)?;
+	let digits: String = raw.chars().filter(|&c| c != ',').collect();
+
+	match digits.parse::<u64>()
+	{
+		Ok(value) => Ok((input, value)),
+		Err(_) => Err(nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Digit))),
+	}
+}
+
+fn parse_region_inner(input: &str) -> IResult<&str, Region>
+{
+	let (input, name) = take_till1(|c: char| c == ':').parse(input)?;
+
+	let (input, range) = opt(preceded(
+		char(':'),
+		(parse_grouped_u64, opt(preceded(char('-'), parse_grouped_u64))),
+	))
+	.parse(input)?;
+
+	let (start, end) = match range
+	{
+		Some((start, end)) => (start.saturating_sub(1), end),
+		None => (0, None),
+	};
+
+	Ok((
+		input,
+		Region {
+			name: name.to_string(),
+			start,
+			end,
+		},
+	))
+}
+
+/// Parses `name`, `name:start`, or `name:start-end`, tolerating comma digit
+/// grouping (`chr1:1,000-2,000`), a sibling to `parse_fai_line`. Rejects any
+/// trailing, unconsumed input instead of silently dropping it.
+pub(crate) fn parse_region(input: &str) -> IResult<&str, Region>
+{
+	all_consuming(parse_region_inner).parse(input)
+}
+
 pub(crate) fn parse_fai_line(input: &str) -> IResult<&str, FaiEntry>
 {
 	let (input, (name, _, length, _, offset, _, line_bases, _, line_width, _)) = ((
@@ -35,3 +99,50 @@ pub(crate) fn parse_fai_line(input: &str) -> IResult<&str, FaiEntry>
 		},
 	))
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn test_parse_region_name_only()
+	{
+		let (_, region) = parse_region("chr1").unwrap();
+		assert_eq!(region, Region { name: "chr1".to_string(), start: 0, end: None });
+	}
+
+	#[test]
+	fn test_parse_region_name_and_start()
+	{
+		let (_, region) = parse_region("chr1:1000").unwrap();
+		assert_eq!(region, Region { name: "chr1".to_string(), start: 999, end: None });
+	}
+
+	#[test]
+	fn test_parse_region_full_range()
+	{
+		let (_, region) = parse_region("chr1:1000-2000").unwrap();
+		assert_eq!(
+			region,
+			Region { name: "chr1".to_string(), start: 999, end: Some(2000) }
+		);
+	}
+
+	#[test]
+	fn test_parse_region_comma_grouping()
+	{
+		let (_, region) = parse_region("chr1:1,000-2,000").unwrap();
+		assert_eq!(
+			region,
+			Region { name: "chr1".to_string(), start: 999, end: Some(2000) }
+		);
+	}
+
+	#[test]
+	fn test_parse_region_rejects_trailing_garbage()
+	{
+		assert!(parse_region("chr1:-5").is_err());
+		assert!(parse_region("chr1:1-4xyz").is_err());
+	}
+}
@@ -0,0 +1,185 @@
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use std::collections::HashMap;
+
+use crate::error;
+use crate::{FaiEntry, FaiIndex};
+
+const DEFAULT_LINE_WIDTH: usize = 60;
+
+/// Writes FASTA records, wrapping each sequence body at a fixed column width.
+///
+/// As records are written their offsets are accumulated, so the writer can
+/// hand back a `FaiIndex` (via `into_index`) that matches the file it just
+/// produced, keeping index and sequence in sync without a separate scan.
+pub struct FastaWriter<W>
+where
+	W: AsyncWrite + std::marker::Unpin,
+{
+	writer: W,
+	line_width: usize,
+	pos: u64,
+	entries: HashMap<String, FaiEntry>,
+}
+
+impl<W> FastaWriter<W>
+where
+	W: AsyncWrite + std::marker::Unpin,
+{
+	pub fn new(writer: W) -> Self
+	{
+		Self::with_line_width(writer, DEFAULT_LINE_WIDTH)
+			.expect("DEFAULT_LINE_WIDTH is non-zero")
+	}
+
+	/// Returns `error::Error::ParseError` if `line_width` is zero, since
+	/// chunking the sequence body into zero-width lines is undefined.
+	pub fn with_line_width(writer: W, line_width: usize) -> error::Result<Self>
+	{
+		if line_width == 0
+		{
+			return Err(error::Error::ParseError);
+		}
+
+		Ok(Self {
+			writer,
+			line_width,
+			pos: 0,
+			entries: HashMap::new(),
+		})
+	}
+
+	pub async fn write_record(
+		&mut self,
+		name: &str,
+		description: Option<&str>,
+		sequence: &str,
+	) -> error::Result<()>
+	{
+		let header = match description
+		{
+			Some(description) => format!(">{} {}\n", name, description),
+			None => format!(">{}\n", name),
+		};
+
+		self.writer.write_all(header.as_bytes()).await?;
+		self.pos += header.len() as u64;
+
+		let offset = self.pos;
+		let mut line_bases = 0u64;
+		let mut line_width = 0u64;
+
+		for (index, chunk) in sequence.as_bytes().chunks(self.line_width).enumerate()
+		{
+			self.writer.write_all(chunk).await?;
+			self.writer.write_all(b"\n").await?;
+
+			if index == 0
+			{
+				line_bases = chunk.len() as u64;
+				line_width = chunk.len() as u64 + 1;
+			}
+
+			self.pos += chunk.len() as u64 + 1;
+		}
+
+		self.entries.insert(
+			name.to_string(),
+			FaiEntry {
+				name: name.to_string(),
+				length: sequence.len() as u64,
+				offset,
+				line_bases,
+				line_width,
+			},
+		);
+
+		Ok(())
+	}
+
+	pub async fn flush(&mut self) -> error::Result<()>
+	{
+		self.writer.flush().await?;
+		Ok(())
+	}
+
+	/// Consumes the writer, returning an `FaiIndex` describing every record
+	/// written so far.
+	pub fn into_index(self) -> FaiIndex
+	{
+		FaiIndex { entries: self.entries }
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::FaiIndex;
+
+	#[tokio::test]
+	async fn test_write_record_wraps_lines()
+	{
+		let mut buf = Vec::new();
+		let mut writer = FastaWriter::with_line_width(&mut buf, 4).unwrap();
+
+		writer
+			.write_record("chr1", None, "ACGTACGTACGT")
+			.await
+			.unwrap();
+		writer.flush().await.unwrap();
+
+		assert_eq!(buf, b">chr1\nACGT\nACGT\nACGT\n");
+	}
+
+	#[tokio::test]
+	async fn test_write_record_with_description()
+	{
+		let mut buf = Vec::new();
+		let mut writer = FastaWriter::with_line_width(&mut buf, 60).unwrap();
+
+		writer
+			.write_record("chr1", Some("example chromosome"), "ACGT")
+			.await
+			.unwrap();
+		writer.flush().await.unwrap();
+
+		assert_eq!(buf, b">chr1 example chromosome\nACGT\n");
+	}
+
+	#[test]
+	fn test_with_line_width_rejects_zero()
+	{
+		let mut buf = Vec::new();
+		assert!(matches!(
+			FastaWriter::with_line_width(&mut buf, 0),
+			Err(error::Error::ParseError)
+		));
+	}
+
+	#[tokio::test]
+	async fn test_into_index_matches_scanned_index()
+	{
+		let mut buf = Vec::new();
+		let mut writer = FastaWriter::with_line_width(&mut buf, 4).unwrap();
+
+		writer
+			.write_record("chr1", None, "ACGTACGTACGT")
+			.await
+			.unwrap();
+		writer.write_record("chr2", None, "TTTT").await.unwrap();
+		writer.flush().await.unwrap();
+
+		let written_index = writer.into_index();
+		let scanned_index = FaiIndex::build_from_reader(&buf[..]).await.unwrap();
+
+		for name in ["chr1", "chr2"]
+		{
+			let written = &written_index.entries[name];
+			let scanned = &scanned_index.entries[name];
+			assert_eq!(written.length, scanned.length);
+			assert_eq!(written.offset, scanned.offset);
+			assert_eq!(written.line_bases, scanned.line_bases);
+			assert_eq!(written.line_width, scanned.line_width);
+		}
+	}
+}